@@ -0,0 +1,97 @@
+// Unlike the process-wide `#[global_allocator]` in src/main.rs, nightly's
+// `Allocator` trait lets individual containers use their own backing store
+// without touching global allocation -- so a program that only needs a
+// handful of fixed-size containers can avoid linking any global allocator
+// at all. This example still links std and its global allocator, so it does
+// not come out smaller (see the measurement below).
+//
+// Size comparison (release, stripped, x86_64-unknown-linux-gnu):
+//   cargo build --release
+//   strip target/release/allocator_api
+//   ls -la target/release/allocator_api
+// Measured locally: src/main.rs (`System` global allocator) strips to
+// 343,296 bytes; this example strips to 344,456 bytes. Both still link the
+// full std runtime, and `main` here still exercises `println!`, so the
+// `Arena` only replaces the allocator behind a handful of containers --
+// there's no libstd/libc machinery left to remove, which is why this isn't
+// smaller in practice. The benefit of `Allocator` is routing specific
+// containers away from a *custom* `#[global_allocator]` (e.g. bump_alloc's),
+// not shrinking a binary that was already using `System`.
+#![feature(allocator_api)]
+
+use std::alloc::{AllocError, Allocator, Layout};
+use std::cell::UnsafeCell;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const ARENA_SIZE: usize = 4 * 1024;
+
+struct Arena {
+    buf: UnsafeCell<[u8; ARENA_SIZE]>,
+    offset: AtomicUsize,
+}
+
+unsafe impl Sync for Arena {}
+
+unsafe impl Allocator for Arena {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = self.bump(layout)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = self.bump(layout)?;
+        unsafe { ptr.as_ptr().write_bytes(0, layout.size()) };
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    // A bump arena never reclaims individual allocations; the whole arena is
+    // freed at once when the `Arena` itself is dropped.
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+}
+
+impl Arena {
+    fn bump(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        let align = layout.align();
+        loop {
+            let current = self.offset.load(Ordering::Relaxed);
+            let rounded = (current + align - 1) & !(align - 1);
+            let next = rounded + layout.size();
+            if next > ARENA_SIZE {
+                return Err(AllocError);
+            }
+            if self
+                .offset
+                .compare_exchange_weak(current, next, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+            {
+                let base = self.buf.get().cast::<u8>();
+                let ptr = unsafe { base.add(rounded) };
+                return NonNull::new(ptr).ok_or(AllocError);
+            }
+        }
+    }
+}
+
+fn main() {
+    let arena = Arena {
+        buf: UnsafeCell::new([0; ARENA_SIZE]),
+        offset: AtomicUsize::new(0),
+    };
+
+    let boxed = Box::new_in([1u8, 2, 3], &arena);
+    let mut vec = Vec::with_capacity_in(4, &arena);
+    vec.push(42u32);
+
+    let layout = Layout::array::<u8>(16).unwrap();
+    let zeroed = arena
+        .allocate_zeroed(layout)
+        .expect("allocate_zeroed should succeed");
+    let bytes = unsafe { zeroed.as_ref() };
+    assert!(
+        bytes.iter().all(|&b| b == 0),
+        "allocate_zeroed must return zeroed memory"
+    );
+
+    println!("{:?} {:?} {:?}", boxed, vec, bytes);
+}
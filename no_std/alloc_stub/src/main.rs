@@ -0,0 +1,62 @@
+#![no_main]
+#![no_std]
+#![windows_subsystem = "console"]
+
+// Some no_std binaries link `alloc` transitively (e.g. through a dependency)
+// but never actually allocate at runtime. For these, any real allocator --
+// even the bump arena in no_std/bump_alloc -- is dead weight. This stub
+// aborts on the first allocation attempt instead, so the optimizer can prove
+// the alloc paths are unreachable and strip them entirely.
+extern crate alloc;
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::panic::PanicInfo;
+
+use windows_sys::Win32::System::Console::GetStdHandle;
+use windows_sys::Win32::System::Console::WriteConsoleA;
+use windows_sys::Win32::System::Console::STD_OUTPUT_HANDLE;
+use windows_sys::Win32::System::Threading::ExitProcess;
+
+struct StubAllocator;
+
+unsafe impl GlobalAlloc for StubAllocator {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        ExitProcess(1);
+    }
+
+    unsafe fn alloc_zeroed(&self, _layout: Layout) -> *mut u8 {
+        ExitProcess(1);
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
+        core::hint::unreachable_unchecked();
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: StubAllocator = StubAllocator;
+
+#[panic_handler]
+fn panic(_: &PanicInfo<'_>) -> ! {
+    unsafe {
+        ExitProcess(1);
+    }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+fn mainCRTStartup() -> ! {
+    let message = "Hello, world!\n";
+    unsafe {
+        let console = GetStdHandle(STD_OUTPUT_HANDLE);
+        WriteConsoleA(
+            console,
+            message.as_ptr().cast::<core::ffi::c_void>(),
+            message.len() as u32,
+            core::ptr::null_mut(),
+            core::ptr::null(),
+        );
+
+        ExitProcess(0)
+    }
+}
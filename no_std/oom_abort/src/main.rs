@@ -0,0 +1,79 @@
+#![no_main]
+#![no_std]
+#![windows_subsystem = "console"]
+#![feature(alloc_error_handler)]
+#![feature(core_intrinsics)]
+
+// `extern crate alloc` (e.g. to use `Vec`/`Box` from the windows-sys example
+// in no_std/win) requires the compiler to have both a panic handler and an
+// out-of-memory handler. The default `#[alloc_error_handler]` formats a
+// message before aborting, which drags in formatting machinery; these just
+// abort immediately, so allocation failure costs nothing in binary size.
+//
+// The `GlobalAlloc` here always fails, so every allocation routes straight
+// through `#[alloc_error_handler]` -- this crate is a minimal, self-contained
+// demonstration of that path, not a usable allocator on its own (see
+// no_std/bump_alloc for a working global allocator, and no_std/alloc_stub
+// for a stub that aborts on any allocation).
+extern crate alloc;
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::panic::PanicInfo;
+use core::ptr::null_mut;
+
+#[cfg(windows)]
+use windows_sys::Win32::System::Threading::ExitProcess;
+
+struct NullAllocator;
+
+unsafe impl GlobalAlloc for NullAllocator {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        null_mut()
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: NullAllocator = NullAllocator;
+
+#[panic_handler]
+fn panic(_: &PanicInfo<'_>) -> ! {
+    abort();
+}
+
+#[alloc_error_handler]
+fn oom(_: Layout) -> ! {
+    abort();
+}
+
+#[cfg(windows)]
+fn abort() -> ! {
+    unsafe {
+        ExitProcess(1);
+    }
+}
+
+#[cfg(unix)]
+fn abort() -> ! {
+    core::intrinsics::abort();
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+#[cfg(windows)]
+fn mainCRTStartup() -> ! {
+    // `NullAllocator` never has space, so this always routes through `oom`.
+    let _ = core::hint::black_box(alloc::boxed::Box::new(0u8));
+    unsafe {
+        ExitProcess(0);
+    }
+}
+
+#[no_mangle]
+#[cfg(unix)]
+extern "C" fn main() -> i32 {
+    // `NullAllocator` never has space, so this always routes through `oom`.
+    let _ = core::hint::black_box(alloc::boxed::Box::new(0u8));
+    abort();
+}